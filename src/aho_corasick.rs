@@ -0,0 +1,126 @@
+//! A small Aho-Corasick automaton used to scan a line of text for several
+//! keywords in a single pass, matching per `char` rather than per byte since
+//! LINE message text has no word boundaries (see [`crate::index`] for the
+//! complementary bigram index, used when repeatedly querying a single
+//! keyword against a whole `History` rather than scanning once for many).
+
+use std::collections::HashMap;
+
+/// A trie with failure links, ready to scan text for a fixed set of keywords.
+pub struct AhoCorasick {
+    /// `goto[node][c]` is the child reached from `node` by `c`, if any.
+    goto: Vec<HashMap<char, usize>>,
+    /// `fail[node]` is the longest proper suffix of `node` that is also a trie node.
+    fail: Vec<usize>,
+    /// `output[node]` holds the indices (into the original keyword slice) of
+    /// every keyword that ends at `node`, including those reached via `fail`.
+    output: Vec<Vec<usize>>,
+}
+
+const ROOT: usize = 0;
+
+impl AhoCorasick {
+    /// Builds the automaton from a set of keywords.
+    #[must_use]
+    pub fn new(keywords: &[&str]) -> Self {
+        let mut automaton = AhoCorasick {
+            goto: vec![HashMap::new()],
+            fail: vec![ROOT],
+            output: vec![Vec::new()],
+        };
+
+        for (pattern_index, keyword) in keywords.iter().enumerate() {
+            automaton.insert(keyword, pattern_index);
+        }
+        automaton.build_failure_links();
+
+        automaton
+    }
+
+    fn insert(&mut self, keyword: &str, pattern_index: usize) {
+        let mut node = ROOT;
+        for c in keyword.chars() {
+            node = if let Some(&child) = self.goto[node].get(&c) {
+                child
+            } else {
+                self.goto.push(HashMap::new());
+                self.fail.push(ROOT);
+                self.output.push(Vec::new());
+                let child = self.goto.len() - 1;
+                self.goto[node].insert(c, child);
+                child
+            };
+        }
+        self.output[node].push(pattern_index);
+    }
+
+    fn build_failure_links(&mut self) {
+        let mut queue: std::collections::VecDeque<usize> = std::collections::VecDeque::new();
+
+        for &child in self.goto[ROOT].values() {
+            self.fail[child] = ROOT;
+            queue.push_back(child);
+        }
+
+        while let Some(node) = queue.pop_front() {
+            let children: Vec<(char, usize)> =
+                self.goto[node].iter().map(|(&c, &child)| (c, child)).collect();
+            for (c, child) in children {
+                let mut fallback = self.fail[node];
+                while fallback != ROOT && !self.goto[fallback].contains_key(&c) {
+                    fallback = self.fail[fallback];
+                }
+                self.fail[child] = self.goto[fallback].get(&c).copied().unwrap_or(ROOT);
+                if self.fail[child] == child {
+                    self.fail[child] = ROOT;
+                }
+                let fail_target = self.fail[child];
+                let mut outputs = self.output[fail_target].clone();
+                self.output[child].append(&mut outputs);
+                queue.push_back(child);
+            }
+        }
+    }
+
+    /// Scans `text` and returns the sorted, deduplicated indices of every
+    /// keyword that occurs in it.
+    #[must_use]
+    pub fn find_matches(&self, text: &str) -> Vec<usize> {
+        let mut node = ROOT;
+        let mut matches = Vec::new();
+
+        for c in text.chars() {
+            while node != ROOT && !self.goto[node].contains_key(&c) {
+                node = self.fail[node];
+            }
+            node = self.goto[node].get(&c).copied().unwrap_or(ROOT);
+            matches.extend(&self.output[node]);
+        }
+
+        matches.sort_unstable();
+        matches.dedup();
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AhoCorasick;
+
+    #[test]
+    fn finds_all_keywords_in_one_pass() {
+        let automaton = AhoCorasick::new(&["おはよう", "おやすみ", "本"]);
+        assert_eq!(automaton.find_matches("おはよう、今日は早いね"), vec![0]);
+        assert_eq!(automaton.find_matches("おやすみ、また明日"), vec![1]);
+        assert_eq!(automaton.find_matches("本を読んでおはよう"), vec![0, 2]);
+        assert!(automaton.find_matches("こんにちは").is_empty());
+    }
+
+    #[test]
+    fn overlapping_keywords_all_reported() {
+        let automaton = AhoCorasick::new(&["abc", "bcd", "c"]);
+        let mut matches = automaton.find_matches("abcd");
+        matches.sort_unstable();
+        assert_eq!(matches, vec![0, 1, 2]);
+    }
+}
@@ -1,12 +1,16 @@
+use std::fmt::Write as _;
+
 use chrono::{Datelike, NaiveDate};
 use text_calendar::YearCalendar;
 
 use crate::history::History;
+use crate::processing::zero_padding;
+use crate::traits::{DayData, HistoryData};
 
 #[allow(clippy::module_name_repetitions)]
 pub use text_calendar::{BasicMarker, Calendar, Marker, MonthCalendar};
 
-impl History {
+impl History<'_> {
     /// Create month calendar.
     #[must_use]
     pub fn create_month_calendar(&self, year: i32, month: u32) -> Option<MonthCalendar> {
@@ -24,7 +28,7 @@ impl History {
     ) -> Option<MonthCalendar> {
         let mut calendar = MonthCalendar::new(year, month, chrono::Weekday::Sun, 4, marker)?;
 
-        for key in self.date_indices.keys() {
+        for key in self.days().keys() {
             if key.year() == year && key.month() == month {
                 calendar.mark(NaiveDate::from_ymd_opt(key.year(), key.month(), key.day()).unwrap());
             }
@@ -47,7 +51,7 @@ impl History {
     ) -> Option<YearCalendar> {
         let mut calendar = YearCalendar::new(year, chrono::Weekday::Sun, 4, marker);
 
-        self.date_indices
+        self.days()
             .keys()
             .filter(|k| k.year() == year)
             .for_each(|key| {
@@ -56,36 +60,97 @@ impl History {
 
         Some(calendar)
     }
+
+    /// Renders a fixed-width table of day, weekday, and chat count for `year`/`month`.
+    #[must_use]
+    pub fn render_count_table(&self, year: i32, month: u32) -> String {
+        let mut rows: Vec<(NaiveDate, usize)> = self
+            .days()
+            .iter()
+            .filter(|(date, _)| date.year() == year && date.month() == month)
+            .map(|(date, day)| (*date, day.chats().len()))
+            .collect();
+        rows.sort_unstable_by_key(|(date, _)| *date);
+
+        let mut table = String::from("Day Weekday    Chats\n");
+        for (date, count) in rows {
+            let _ = writeln!(
+                table,
+                "{} {:<9} {}",
+                zero_padding(date.day() as usize, 2),
+                date.weekday(),
+                count
+            );
+        }
+        table
+    }
+
+    /// Renders a month-by-weekday intensity heatmap of message counts for `year`.
+    ///
+    /// Each cell is the day of that weekday/month with the most chats,
+    /// bucketed into a ramp of density characters relative to the year's
+    /// busiest day.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    pub fn render_heatmap(&self, year: i32) -> String {
+        const RAMP: [char; 8] = [' ', '.', ':', '-', '=', '+', '*', '#'];
+
+        let mut grid = [[0usize; 7]; 12];
+        let mut max = 0usize;
+
+        for (date, day) in self.days() {
+            if date.year() != year {
+                continue;
+            }
+            let cell = &mut grid[date.month0() as usize][date.weekday().num_days_from_sunday() as usize];
+            *cell = (*cell).max(day.chats().len());
+            max = max.max(*cell);
+        }
+
+        let mut heatmap = String::from("    Su Mo Tu We Th Fr Sa\n");
+        for (month_index, row) in grid.iter().enumerate() {
+            heatmap.push_str(&zero_padding(month_index + 1, 2));
+            for &count in row {
+                let symbol = if count == 0 {
+                    ' '
+                } else {
+                    match (count * (RAMP.len() - 1)).checked_div(max) {
+                        Some(bucket) => RAMP[bucket.max(1).min(RAMP.len() - 1)],
+                        None => RAMP[0],
+                    }
+                };
+                let _ = write!(heatmap, "  {symbol} ");
+            }
+            heatmap.push('\n');
+        }
+        heatmap
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse::parse_history;
 
-    const CONTENT: &str = "[LINE]MyGroupのトーク履歴
-保存日時：2024/01/01 00:00
-
-2024/02/01(木)
-00:00\tA\tおはよう
+    const CONTENT: &str = "2024/02/01(木)\r
+00:00\tA\tおはよう\r
 
-2024/02/11(日)
-00:00\tA\tおはよう
+2024/02/11(日)\r
+00:00\tA\tおはよう\r
 
-2024/02/15(木)
-00:00\tA\tおはよう
+2024/02/15(木)\r
+00:00\tA\tおはよう\r
 
-2024/02/26(月)
-00:00\tA\tおはよう
+2024/02/26(月)\r
+00:00\tA\tおはよう\r
 
-2024/02/29(木)
-23:59\tA\t\"おやすみ
-2024/02/01(木)
-00:00\tA\tおはよう\"
+2024/02/29(木)\r
+23:59\tA\tおやすみ\r
 ";
 
     #[test]
     fn cal_test() {
-        let history = History::new(CONTENT);
+        let history = parse_history(CONTENT).unwrap();
         let calendar = history.create_month_calendar(2024, 2).unwrap();
         let expected = "          February          
  Su  Mo  Tu  We  Th  Fr  Sa 
@@ -98,3 +163,53 @@ mod tests {
         assert_eq!(calendar.to_string(), expected);
     }
 }
+
+#[cfg(test)]
+mod render_tests {
+    use super::*;
+    use crate::parse::parse_history;
+
+    const CONTENT: &str = "2024/02/01(木)\r
+00:00\tA\tおはよう\r
+09:00\tB\tおはよう\r
+\r
+2024/02/02(金)\r
+09:00\tB\tおやすみ\r
+";
+
+    #[test]
+    fn count_table_lists_each_day_in_the_month() {
+        let history = parse_history(CONTENT).unwrap();
+        let table = history.render_count_table(2024, 2);
+
+        assert!(table.contains("01 Thu       2"));
+        assert!(table.contains("02 Fri       1"));
+    }
+
+    #[test]
+    fn heatmap_marks_the_busiest_day_most_densely() {
+        let history = parse_history(CONTENT).unwrap();
+        let heatmap = history.render_heatmap(2024);
+
+        let february_row: &str = heatmap.lines().nth(2).unwrap();
+        assert!(february_row.contains('#'));
+    }
+
+    #[test]
+    fn heatmap_does_not_render_low_but_nonzero_days_as_blank() {
+        let mut content = String::from("2024/02/01(木)\r\n");
+        for _ in 0..50 {
+            content.push_str("00:00\tA\tおはよう\r\n");
+        }
+        content.push_str("\r\n2024/02/02(金)\r\n00:00\tB\tおやすみ\r\n");
+
+        let history = parse_history(&content).unwrap();
+        let heatmap = history.render_heatmap(2024);
+
+        // Row layout: "MM" + 7 cells of "  X ", one per weekday starting Sunday.
+        // Friday (index 5) has 1 chat against a busiest day of 50.
+        let february_row: &str = heatmap.lines().nth(2).unwrap();
+        let friday_symbol = february_row.chars().nth(2 + 5 * 4 + 2).unwrap();
+        assert_ne!(friday_symbol, ' ');
+    }
+}
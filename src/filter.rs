@@ -0,0 +1,81 @@
+//! Filtering a [`History`] by calendar structure — weekday or time of day —
+//! rather than by exact date or keyword.
+
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+
+use crate::history::{Chat, Day, History};
+use crate::traits::{ChatData, DayData, HistoryData};
+
+impl<'src> History<'src> {
+    /// All days whose date falls on `wd`.
+    #[must_use = "this returns the filtered days and does not filter in place"]
+    pub fn filter_by_weekday(&self, wd: Weekday) -> impl Iterator<Item = &Day<'src>> {
+        self.days().values().filter(move |day| day.date().weekday() == wd)
+    }
+
+    /// All chats whose time falls in the half-open interval `[from, to)`.
+    ///
+    /// When `from > to`, the interval is treated as wrapping past midnight
+    /// (e.g. 23:00–02:00).
+    #[must_use = "this returns the filtered chats and does not filter in place"]
+    pub fn filter_by_hour_range(
+        &self,
+        from: NaiveTime,
+        to: NaiveTime,
+    ) -> impl Iterator<Item = (NaiveDate, &Chat<'src>)> {
+        self.days().values().flat_map(move |day| {
+            day.chats().iter().filter_map(move |chat| {
+                let t = *chat.time();
+                let in_range = if from > to { from <= t || t < to } else { from <= t && t < to };
+                in_range.then_some((*day.date(), chat))
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_history;
+
+    const CONTENT: &str = "2024/02/01(木)\r
+09:00\tA\tおはよう\r
+23:30\tA\tおやすみ\r
+\r
+2024/02/04(日)\r
+01:00\tB\t夜更かし\r
+12:00\tB\tこんにちは\r
+";
+
+    #[test]
+    fn filters_by_weekday() {
+        let history = parse_history(CONTENT).unwrap();
+        let dates: Vec<NaiveDate> = history
+            .filter_by_weekday(Weekday::Sun)
+            .map(|day| *day.date())
+            .collect();
+
+        assert_eq!(dates, vec![NaiveDate::from_ymd_opt(2024, 2, 4).unwrap()]);
+    }
+
+    #[test]
+    fn filters_by_wrapping_hour_range() {
+        let history = parse_history(CONTENT).unwrap();
+        let from = NaiveTime::from_hms_opt(23, 0, 0).unwrap();
+        let to = NaiveTime::from_hms_opt(2, 0, 0).unwrap();
+
+        let mut times: Vec<NaiveTime> = history
+            .filter_by_hour_range(from, to)
+            .map(|(_, chat)| *chat.time())
+            .collect();
+        times.sort();
+
+        assert_eq!(
+            times,
+            vec![
+                NaiveTime::from_hms_opt(1, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(23, 30, 0).unwrap(),
+            ]
+        );
+    }
+}
@@ -1,9 +1,14 @@
 use chrono::{NaiveDate, NaiveTime};
+#[cfg(feature = "rand")]
 use rand::Rng;
 use std::collections::HashMap;
 
+use crate::aho_corasick::AhoCorasick;
+#[cfg(feature = "rand")]
+use crate::traits::SearchByRandom;
 use crate::traits::{
-    ChatData, DayData, HistoryData, Search, SearchByDate, SearchByKeyword, SearchByRandom,
+    ChatData, DayData, HistoryData, Search, SearchByDate, SearchByKeyword, SearchByKeywordResult,
+    SearchByKeywords,
 };
 
 /// 履歴全体
@@ -37,13 +42,32 @@ impl<'src> SearchByDate for History<'src> {
 
 impl<'src> SearchByKeyword for History<'src> {
     type Chat = Chat<'src>;
-    fn search_by_keyword(&self, keyword: &str) -> impl Iterator<Item = (NaiveDate, &Self::Chat)> {
+    fn search_by_keyword(
+        &self,
+        keyword: &str,
+    ) -> impl Iterator<Item = SearchByKeywordResult<'_, Self::Chat>> {
         self.days
             .values()
             .flat_map(move |day| day.search_by_keyword(keyword))
     }
 }
 
+impl<'src> SearchByKeywords for History<'src> {
+    type Chat = Chat<'src>;
+    fn search_by_keywords<'k>(
+        &self,
+        keywords: &'k [&'k str],
+    ) -> impl Iterator<Item = SearchByKeywordResult<'_, Self::Chat>> {
+        let automaton = AhoCorasick::new(keywords);
+        self.days
+            .values()
+            .flat_map(|day| day.search_by_keywords_with(&automaton))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(feature = "rand")]
 impl<'src> SearchByRandom for History<'src> {
     type Day = Day<'src>;
     fn search_by_random(&self) -> &Self::Day {
@@ -83,11 +107,68 @@ pub struct Day<'src> {
 
 impl<'src> SearchByKeyword for Day<'src> {
     type Chat = Chat<'src>;
-    fn search_by_keyword(&self, keyword: &str) -> impl Iterator<Item = (NaiveDate, &Self::Chat)> {
+    fn search_by_keyword(
+        &self,
+        keyword: &str,
+    ) -> impl Iterator<Item = SearchByKeywordResult<'_, Self::Chat>> {
         self.chats
             .iter()
-            .map(move |chat| (self.date, chat))
+            .enumerate()
             .filter(move |(_, chat)| chat.contains(keyword))
+            .map(move |(index, chat)| SearchByKeywordResult {
+                date: self.date,
+                chat,
+                index,
+                matched_keywords: Vec::new(),
+            })
+    }
+}
+
+impl<'src> SearchByKeywords for Day<'src> {
+    type Chat = Chat<'src>;
+    fn search_by_keywords<'k>(
+        &self,
+        keywords: &'k [&'k str],
+    ) -> impl Iterator<Item = SearchByKeywordResult<'_, Self::Chat>> {
+        let automaton = AhoCorasick::new(keywords);
+        self.search_by_keywords_with(&automaton).into_iter()
+    }
+}
+
+impl<'src> Day<'src> {
+    /// Scans this day's chats with an already-built automaton, avoiding
+    /// rebuilding it for every day when searching a whole `History`.
+    ///
+    /// Returns an owned `Vec` (rather than a lazy iterator) so that the
+    /// result doesn't borrow `automaton`, which is typically a value local to
+    /// the caller and shorter-lived than `self`.
+    fn search_by_keywords_with<'a>(
+        &'a self,
+        automaton: &AhoCorasick,
+    ) -> Vec<SearchByKeywordResult<'a, Chat<'src>>> {
+        self.chats
+            .iter()
+            .enumerate()
+            .filter_map(|(index, chat)| {
+                let matched_keywords = chat
+                    .message_lines
+                    .iter()
+                    .flat_map(|line| automaton.find_matches(line))
+                    .collect::<std::collections::BTreeSet<_>>()
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                if matched_keywords.is_empty() {
+                    None
+                } else {
+                    Some(SearchByKeywordResult {
+                        date: self.date,
+                        chat,
+                        index,
+                        matched_keywords,
+                    })
+                }
+            })
+            .collect()
     }
 }
 
@@ -139,11 +220,33 @@ impl Chat<'_> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OwnedHistory {
     pub days: HashMap<NaiveDate, OwnedDay>,
 }
 
+#[cfg(feature = "serde")]
+impl OwnedHistory {
+    /// Serializes this history to a JSON string, so it can be cached and
+    /// reloaded without re-running [`crate::parse::parse_history`].
+    ///
+    /// # Panics
+    /// Panics if serialization fails, which shouldn't happen for this type.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("OwnedHistory should always serialize to JSON")
+    }
+
+    /// Deserializes a history previously produced by [`OwnedHistory::to_json`].
+    ///
+    /// # Errors
+    /// Returns an error if `json` isn't valid JSON matching this shape.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
 impl Search for OwnedHistory {}
 
 impl SearchByDate for OwnedHistory {
@@ -156,13 +259,32 @@ impl SearchByDate for OwnedHistory {
 
 impl SearchByKeyword for OwnedHistory {
     type Chat = OwnedChat;
-    fn search_by_keyword(&self, keyword: &str) -> impl Iterator<Item = (NaiveDate, &Self::Chat)> {
+    fn search_by_keyword(
+        &self,
+        keyword: &str,
+    ) -> impl Iterator<Item = SearchByKeywordResult<'_, Self::Chat>> {
         self.days
             .values()
             .flat_map(move |day| day.search_by_keyword(keyword))
     }
 }
 
+impl SearchByKeywords for OwnedHistory {
+    type Chat = OwnedChat;
+    fn search_by_keywords<'k>(
+        &self,
+        keywords: &'k [&'k str],
+    ) -> impl Iterator<Item = SearchByKeywordResult<'_, Self::Chat>> {
+        let automaton = AhoCorasick::new(keywords);
+        self.days
+            .values()
+            .flat_map(|day| day.search_by_keywords_with(&automaton))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(feature = "rand")]
 impl SearchByRandom for OwnedHistory {
     type Day = OwnedDay;
     fn search_by_random(&self) -> &Self::Day {
@@ -213,7 +335,8 @@ impl OwnedHistory {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OwnedDay {
     pub date: NaiveDate,
     pub chats: Vec<OwnedChat>,
@@ -231,11 +354,68 @@ impl DayData<OwnedChat> for OwnedDay {
 
 impl SearchByKeyword for OwnedDay {
     type Chat = OwnedChat;
-    fn search_by_keyword(&self, keyword: &str) -> impl Iterator<Item = (NaiveDate, &Self::Chat)> {
+    fn search_by_keyword(
+        &self,
+        keyword: &str,
+    ) -> impl Iterator<Item = SearchByKeywordResult<'_, Self::Chat>> {
         self.chats
             .iter()
-            .map(move |chat| (self.date, chat))
+            .enumerate()
             .filter(move |(_, chat)| chat.contains(keyword))
+            .map(move |(index, chat)| SearchByKeywordResult {
+                date: self.date,
+                chat,
+                index,
+                matched_keywords: Vec::new(),
+            })
+    }
+}
+
+impl SearchByKeywords for OwnedDay {
+    type Chat = OwnedChat;
+    fn search_by_keywords<'k>(
+        &self,
+        keywords: &'k [&'k str],
+    ) -> impl Iterator<Item = SearchByKeywordResult<'_, Self::Chat>> {
+        let automaton = AhoCorasick::new(keywords);
+        self.search_by_keywords_with(&automaton).into_iter()
+    }
+}
+
+impl OwnedDay {
+    /// Scans this day's chats with an already-built automaton, avoiding
+    /// rebuilding it for every day when searching a whole `OwnedHistory`.
+    ///
+    /// Returns an owned `Vec` (rather than a lazy iterator) so that the
+    /// result doesn't borrow `automaton`, which is typically a value local to
+    /// the caller and shorter-lived than `self`.
+    fn search_by_keywords_with<'a>(
+        &'a self,
+        automaton: &AhoCorasick,
+    ) -> Vec<SearchByKeywordResult<'a, OwnedChat>> {
+        self.chats
+            .iter()
+            .enumerate()
+            .filter_map(|(index, chat)| {
+                let matched_keywords = chat
+                    .message_lines
+                    .iter()
+                    .flat_map(|line| automaton.find_matches(line))
+                    .collect::<std::collections::BTreeSet<_>>()
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                if matched_keywords.is_empty() {
+                    None
+                } else {
+                    Some(SearchByKeywordResult {
+                        date: self.date,
+                        chat,
+                        index,
+                        matched_keywords,
+                    })
+                }
+            })
+            .collect()
     }
 }
 
@@ -262,7 +442,8 @@ impl OwnedDay {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OwnedChat {
     pub time: NaiveTime,
     pub speaker: Option<String>,
@@ -317,3 +498,35 @@ pub fn ignore_errors<'src, E>(
         Err((history_incomplete, _)) => history_incomplete,
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use crate::parse::parse_history;
+
+    const CONTENT: &str = "2024/02/01(木)\r
+09:00\tA\tおはよう\r
+23:30\tA\tおやすみ\r
+\r
+2024/02/04(日)\r
+01:00\tB\t夜更かし\r
+12:00\t\tこんにちは\r
+";
+
+    #[test]
+    fn to_json_then_from_json_round_trips_unchanged() {
+        let history = parse_history(CONTENT).unwrap().into_owned();
+
+        let json = history.to_json();
+        let restored = OwnedHistory::from_json(&json).unwrap();
+
+        assert_eq!(restored, history);
+    }
+
+    #[test]
+    fn from_json_rejects_malformed_json() {
+        let result = OwnedHistory::from_json("not json");
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,156 @@
+//! A bigram inverted index over a [`History`], giving sub-linear keyword
+//! search for interactive use over a large `history.txt` (unlike
+//! [`crate::aho_corasick`], which scans once for many keywords, this trades a
+//! one-time build cost for fast repeated single-keyword lookups). Bigrams
+//! rather than whole words are used as keys for the same reason
+//! `aho_corasick` matches per `char`: LINE message text has no word
+//! boundaries.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDate;
+
+use crate::history::{Chat, History};
+use crate::traits::{ChatData, DayData, HistoryData, SearchByKeyword, SearchByKeywordResult};
+
+/// A single occurrence of a bigram: the day it was said on, and the position
+/// of the chat within that day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Posting {
+    date: NaiveDate,
+    chat_index: usize,
+}
+
+/// A bigram inverted index built from a [`History`].
+pub struct Index<'src> {
+    history: &'src History<'src>,
+    postings: HashMap<[char; 2], Vec<Posting>>,
+}
+
+impl<'src> Index<'src> {
+    /// Builds the index by sliding a 2-character window over every message
+    /// line in `history`.
+    #[must_use]
+    pub fn new(history: &'src History<'src>) -> Self {
+        let mut postings: HashMap<[char; 2], Vec<Posting>> = HashMap::new();
+
+        for day in history.days().values() {
+            for (chat_index, chat) in day.chats().iter().enumerate() {
+                for line in chat.message_lines() {
+                    let chars: Vec<char> = line.chars().collect();
+                    for window in chars.windows(2) {
+                        let bigram = [window[0], window[1]];
+                        let list = postings.entry(bigram).or_default();
+                        let posting = Posting { date: *day.date(), chat_index };
+                        if list.last() != Some(&posting) {
+                            list.push(posting);
+                        }
+                    }
+                }
+            }
+        }
+
+        Self { history, postings }
+    }
+
+    /// Searches for `keyword`.
+    ///
+    /// The keyword is decomposed into its bigrams, whose posting lists are
+    /// intersected (shortest list first) to find candidate chats, which are
+    /// then verified with [`ChatData::contains`] to rule out false positives
+    /// from non-adjacent bigram coincidences. Single-character keywords fall
+    /// back to a linear scan, since they have no bigram to index on.
+    #[must_use = "this returns the matching chats and does not search in place"]
+    pub fn search(&self, keyword: &str) -> impl Iterator<Item = SearchByKeywordResult<'src, Chat<'src>>> {
+        let chars: Vec<char> = keyword.chars().collect();
+        if chars.len() < 2 {
+            return self
+                .history
+                .search_by_keyword(keyword)
+                .collect::<Vec<_>>()
+                .into_iter();
+        }
+
+        let bigrams: Vec<[char; 2]> = chars.windows(2).map(|w| [w[0], w[1]]).collect();
+        let mut lists: Vec<&Vec<Posting>> = Vec::with_capacity(bigrams.len());
+        for bigram in &bigrams {
+            let Some(list) = self.postings.get(bigram) else {
+                return Vec::new().into_iter();
+            };
+            lists.push(list);
+        }
+        lists.sort_by_key(|list| list.len());
+
+        let mut candidates = lists[0].clone();
+        for list in &lists[1..] {
+            let set: HashSet<Posting> = list.iter().copied().collect();
+            candidates.retain(|posting| set.contains(posting));
+        }
+        candidates.sort_by_key(|posting| (posting.date, posting.chat_index));
+        candidates.dedup();
+
+        candidates
+            .into_iter()
+            .filter_map(|posting| {
+                let day = self.history.days().get(&posting.date)?;
+                let chat = day.chats().get(posting.chat_index)?;
+                chat.contains(keyword).then_some(SearchByKeywordResult {
+                    date: posting.date,
+                    chat,
+                    index: posting.chat_index,
+                    matched_keywords: Vec::new(),
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_history;
+
+    const CONTENT: &str = "2024/02/01(木)\r
+00:00\tA\tおはよう\r
+\r
+2024/02/02(金)\r
+09:00\tB\tおやすみ\r
+10:00\tA\t今日はおはようと言わなかった\r
+";
+
+    #[test]
+    fn finds_indexed_keyword() {
+        let history = parse_history(CONTENT).unwrap();
+        let index = Index::new(&history);
+
+        let mut dates: Vec<NaiveDate> = index.search("おはよう").map(|r| r.date).collect();
+        dates.sort();
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 2).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn falls_back_to_linear_scan_for_single_char() {
+        let history = parse_history(CONTENT).unwrap();
+        let index = Index::new(&history);
+
+        let count = index.search("お").count();
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn rejects_non_adjacent_bigram_coincidences() {
+        let history = parse_history(CONTENT).unwrap();
+        let index = Index::new(&history);
+
+        assert_eq!(index.search("おはようと言わなかった").count(), 1);
+        // Every bigram of "日はよう" occurs in the last chat, but not contiguously.
+        assert_eq!(index.search("日はよう").count(), 0);
+    }
+}
@@ -1,8 +1,12 @@
 #![warn(clippy::pedantic)]
 
+pub mod aho_corasick;
+pub mod calendar;
+pub mod filter;
 pub mod history;
+pub mod index;
 pub mod macros;
 pub mod parse;
+pub mod processing;
+pub mod stats;
 pub mod traits;
-#[cfg(feature = "rand")]
-pub mod rand;
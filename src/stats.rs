@@ -0,0 +1,137 @@
+//! Date-range queries and derived activity statistics over a [`History`].
+
+use chrono::NaiveDate;
+
+use crate::history::{Day, History};
+use crate::traits::{DayData, HistoryData, SearchByDate};
+
+impl<'src> History<'src> {
+    /// Walks consecutive calendar days from `start` through `end` (inclusive),
+    /// yielding only the dates that have a recorded day.
+    #[must_use = "this returns the matching days and does not search in place"]
+    pub fn search_by_date_range(
+        &self,
+        start: &NaiveDate,
+        end: &NaiveDate,
+    ) -> impl Iterator<Item = &Day<'src>> {
+        let end = *end;
+        let mut next = Some(*start);
+
+        std::iter::from_fn(move || {
+            let date = next.filter(|date| *date <= end)?;
+            next = date.succ_opt();
+            Some(date)
+        })
+        .filter_map(move |date| self.search_by_date(&date))
+    }
+
+    /// The longest run of consecutive days that each contain at least one
+    /// chat, as `(start, end, length)`.
+    #[must_use]
+    pub fn longest_streak(&self) -> Option<(NaiveDate, NaiveDate, u32)> {
+        let mut dates: Vec<NaiveDate> = self
+            .days()
+            .values()
+            .filter(|day| !day.chats().is_empty())
+            .map(|day| *day.date())
+            .collect();
+        dates.sort_unstable();
+
+        let mut dates = dates.into_iter();
+        let first = dates.next()?;
+
+        let mut best = (first, first, 1u32);
+        let mut current = best;
+
+        for date in dates {
+            if Some(date) == current.1.succ_opt() {
+                current.1 = date;
+                current.2 += 1;
+            } else {
+                current = (date, date, 1);
+            }
+
+            if current.2 > best.2 {
+                best = current;
+            }
+        }
+
+        Some(best)
+    }
+
+    /// The number of distinct days that have at least one chat.
+    #[must_use]
+    pub fn total_days_active(&self) -> usize {
+        self.days().values().filter(|day| !day.chats().is_empty()).count()
+    }
+
+    /// The day with the most chats, if any days are recorded.
+    #[must_use]
+    pub fn busiest_day(&self) -> Option<&Day<'src>> {
+        self.days().values().max_by_key(|day| day.chats().len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_history;
+
+    const CONTENT: &str = "2024/02/01(木)\r
+00:00\tA\tおはよう\r
+\r
+2024/02/02(金)\r
+09:00\tB\tおはよう\r
+10:00\tA\tおはよう\r
+\r
+2024/02/04(日)\r
+09:00\tB\tおはよう\r
+";
+
+    #[test]
+    fn range_skips_missing_days() {
+        let history = parse_history(CONTENT).unwrap();
+        let start = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 2, 4).unwrap();
+
+        let dates: Vec<NaiveDate> = history
+            .search_by_date_range(&start, &end)
+            .map(|day| *day.date())
+            .collect();
+
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 2).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 4).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn longest_streak_finds_longest_run() {
+        let history = parse_history(CONTENT).unwrap();
+        let streak = history.longest_streak().unwrap();
+
+        assert_eq!(
+            streak,
+            (
+                NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 2).unwrap(),
+                2
+            )
+        );
+    }
+
+    #[test]
+    fn total_days_active_and_busiest_day() {
+        let history = parse_history(CONTENT).unwrap();
+
+        assert_eq!(history.total_days_active(), 3);
+        assert_eq!(
+            *history.busiest_day().unwrap().date(),
+            NaiveDate::from_ymd_opt(2024, 2, 2).unwrap()
+        );
+    }
+}
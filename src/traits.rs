@@ -15,7 +15,16 @@ pub trait SearchByDate {
 
 pub trait SearchByKeyword {
     type Chat: ChatData;
-    fn search_by_keyword(&self, keyword: &str) -> impl Iterator<Item = SearchByKeywordResult<Self::Chat>>;
+    fn search_by_keyword(&self, keyword: &str) -> impl Iterator<Item = SearchByKeywordResult<'_, Self::Chat>>;
+}
+
+/// Searches multiple keywords in a single pass using an Aho-Corasick automaton.
+pub trait SearchByKeywords {
+    type Chat: ChatData;
+    fn search_by_keywords<'k>(
+        &self,
+        keywords: &'k [&'k str],
+    ) -> impl Iterator<Item = SearchByKeywordResult<'_, Self::Chat>>;
 }
 
 #[derive(Debug)]
@@ -23,6 +32,9 @@ pub struct SearchByKeywordResult<'a, C: ChatData> {
     pub date: NaiveDate,
     pub chat: &'a C,
     pub index: usize,
+    /// Indices (into the queried keyword slice) of the keywords that matched this chat.
+    /// Empty for single-keyword searches.
+    pub matched_keywords: Vec<usize>,
 }
 
 pub trait SearchByRandom {